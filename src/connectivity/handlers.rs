@@ -0,0 +1,155 @@
+use crate::connectivity::WifiConnectionError;
+use crate::platforms::Credential;
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+/// Builds the Windows WLAN profile XML `netsh wlan add profile` expects for
+/// a given SSID/credential, then writes it to a temporary file so it can be
+/// handed to `netsh` by filename.
+pub struct NetworkXmlProfileHandler {
+    pub content: String,
+}
+
+impl NetworkXmlProfileHandler {
+    pub fn new(ssid: &str, credential: &Credential) -> Self {
+        let (authentication, encryption, shared_key) = match credential {
+            Credential::Open => ("open", "none", None),
+            Credential::Wep(key) => ("open", "WEP", Some(("networkKey", key.0.as_str()))),
+            Credential::WpaPersonal(passphrase) => {
+                ("WPA2PSK", "AES", Some(("passPhrase", passphrase.0.as_str())))
+            }
+            Credential::WpaPersonalPsk(psk) => {
+                ("WPA2PSK", "AES", Some(("networkKey", psk.0.as_str())))
+            }
+            Credential::Wpa3Sae(passphrase) => {
+                ("WPA3SAE", "AES", Some(("passPhrase", passphrase.0.as_str())))
+            }
+            // 802.1X profiles authenticate via EAP, not a shared key; the
+            // identity/password go into the <OneX> EAPConfig block below.
+            Credential::WpaEnterprise { .. } => ("WPA2", "AES", None),
+        };
+
+        let security = match shared_key {
+            Some((key_type, key)) => format!(
+                r#"<authEncryption>
+                <authentication>{authentication}</authentication>
+                <encryption>{encryption}</encryption>
+                <useOneX>false</useOneX>
+            </authEncryption>
+            <sharedKey>
+                <keyType>{key_type}</keyType>
+                <protected>false</protected>
+                <keyMaterial>{key}</keyMaterial>
+            </sharedKey>"#,
+                authentication = authentication,
+                encryption = encryption,
+                key_type = key_type,
+                key = xml_escape(key)
+            ),
+            None => format!(
+                r#"<authEncryption>
+                <authentication>{authentication}</authentication>
+                <encryption>{encryption}</encryption>
+                <useOneX>{use_one_x}</useOneX>
+            </authEncryption>{one_x}"#,
+                authentication = authentication,
+                encryption = encryption,
+                use_one_x = matches!(credential, Credential::WpaEnterprise { .. }),
+                one_x = one_x_config(credential),
+            ),
+        };
+
+        let content = format!(
+            r#"<?xml version="1.0"?>
+<WLANProfile xmlns="http://www.microsoft.com/networking/WLAN/profile/v1">
+    <name>{ssid}</name>
+    <SSIDConfig>
+        <SSID>
+            <name>{ssid}</name>
+        </SSID>
+    </SSIDConfig>
+    <connectionType>ESS</connectionType>
+    <connectionMode>manual</connectionMode>
+    <MSM>
+        <security>
+            {security}
+        </security>
+    </MSM>
+</WLANProfile>"#,
+            ssid = xml_escape(ssid),
+            security = security
+        );
+
+        NetworkXmlProfileHandler { content }
+    }
+
+    /// Writes `content` to a temporary file so `netsh wlan add profile
+    /// filename=...` can read it.
+    pub fn write_to_temp_file(&self) -> Result<NamedTempFile, WifiConnectionError> {
+        let mut temp_file = NamedTempFile::new().map_err(WifiConnectionError::Io)?;
+        temp_file
+            .write_all(self.content.as_bytes())
+            .map_err(WifiConnectionError::Io)?;
+
+        Ok(temp_file)
+    }
+}
+
+/// Builds the `<OneX>` EAP configuration block `WpaEnterprise` profiles need.
+///
+/// `netsh wlan add profile` has no separate flag for EAP credentials — the
+/// only way to hand it an identity/password up front (rather than prompting
+/// interactively at connect time) is to embed them directly in the profile's
+/// `EAPConfig` blob, the same place `netsh wlan export profile` writes them
+/// back out to when a saved profile already has credentials attached. This
+/// uses EAP type 25 (PEAP) wrapping type 26 (EAP-MSCHAPv2), which is what
+/// Windows defaults to for a generic enterprise network.
+fn one_x_config(credential: &Credential) -> String {
+    let (identity, password) = match credential {
+        Credential::WpaEnterprise { identity, password } => (identity, password),
+        _ => return String::new(),
+    };
+
+    format!(
+        r#"
+            <OneX xmlns="http://www.microsoft.com/networking/OneX/v1">
+                <EAPConfig>
+                    <EapHostConfig xmlns="http://www.microsoft.com/provisioning/EapHostConfig">
+                        <EapMethod>
+                            <Type xmlns="http://www.microsoft.com/provisioning/EapCommon">25</Type>
+                        </EapMethod>
+                        <Config xmlns="http://www.microsoft.com/provisioning/EapHostConfig">
+                            <Eap xmlns="http://www.microsoft.com/provisioning/BaseEapConnectionPropertiesV1">
+                                <Type>25</Type>
+                                <EapType xmlns="http://www.microsoft.com/provisioning/MsPeapConnectionPropertiesV1">
+                                    <RoutingIdentity>{identity}</RoutingIdentity>
+                                    <EapType xmlns="http://www.microsoft.com/provisioning/BaseEapConnectionPropertiesV1">
+                                        <Type>26</Type>
+                                        <EapType xmlns="http://www.microsoft.com/provisioning/MsChapV2ConnectionPropertiesV1">
+                                            <UseWinLogonCredentials>false</UseWinLogonCredentials>
+                                            <Username>{identity}</Username>
+                                            <Password>{password}</Password>
+                                        </EapType>
+                                    </EapType>
+                                </EapType>
+                            </Eap>
+                        </Config>
+                    </EapHostConfig>
+                </EAPConfig>
+            </OneX>"#,
+        identity = xml_escape(identity),
+        password = xml_escape(password),
+    )
+}
+
+/// Escapes the five characters XML requires it for use in element text
+/// content, so an SSID/passphrase/identity containing `&`, `<`, `>`, `"`, or
+/// `'` can't corrupt the generated profile or break out of its element.
+fn xml_escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
@@ -0,0 +1,91 @@
+pub mod handlers;
+pub mod providers;
+
+use crate::platforms::{AvailableWifi, Credential, WifiError};
+use std::fmt;
+use std::io;
+
+/// Wireless connect/disconnect/scan operations implemented by each
+/// platform's backend.
+pub trait Connectivity {
+    /// Attempts to connect to a wireless network with a given SSID, using
+    /// `credential` to authenticate against whatever scheme the network
+    /// requires.
+    fn connect(&mut self, ssid: &str, credential: &Credential) -> Result<bool, WifiConnectionError>;
+    /// Attempts to disconnect from a wireless network currently connected to.
+    fn disconnect(&self) -> Result<bool, WifiConnectionError>;
+    /// Scan for available networks.
+    fn scan(&self) -> Result<Vec<AvailableWifi>, WifiConnectionError>;
+}
+
+/// Errors surfaced while connecting to, disconnecting from, or adding a
+/// profile for a wireless network. Each variant carries the context needed
+/// to recover (retry, prompt for a different password, fall back to another
+/// interface) instead of forcing callers to pattern-match on a message.
+#[derive(Debug)]
+pub enum WifiConnectionError {
+    /// Failed to associate `iface` with `ssid`.
+    Connect {
+        ssid: String,
+        iface: String,
+        source: io::Error,
+    },
+    /// Failed to bring `iface` down.
+    Disconnect { iface: String },
+    /// Failed to register a connection profile for `ssid`.
+    AddProfile { ssid: String },
+    /// A line of scan output did not match the expected format.
+    ScanParse { line: String },
+    /// The wireless radio is disabled.
+    WifiDisabled,
+    /// An I/O failure not covered by a more specific variant, e.g. while
+    /// checking whether the radio is enabled, running a scan, writing a
+    /// temporary profile file, or talking to the `wpa_supplicant` control
+    /// socket.
+    Io(io::Error),
+}
+
+impl fmt::Display for WifiConnectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WifiConnectionError::Connect { ssid, iface, source } => write!(
+                f,
+                "failed to connect to \"{}\" on interface \"{}\": {}",
+                ssid, iface, source
+            ),
+            WifiConnectionError::Disconnect { iface } => {
+                write!(f, "failed to disconnect interface \"{}\"", iface)
+            }
+            WifiConnectionError::AddProfile { ssid } => {
+                write!(f, "failed to add connection profile for \"{}\"", ssid)
+            }
+            WifiConnectionError::ScanParse { line } => {
+                write!(f, "failed to parse scan output line: \"{}\"", line)
+            }
+            WifiConnectionError::WifiDisabled => write!(f, "wifi is disabled"),
+            WifiConnectionError::Io(source) => write!(f, "{}", source),
+        }
+    }
+}
+
+impl std::error::Error for WifiConnectionError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WifiConnectionError::Connect { source, .. } => Some(source),
+            WifiConnectionError::Io(source) => Some(source),
+            WifiConnectionError::Disconnect { .. }
+            | WifiConnectionError::AddProfile { .. }
+            | WifiConnectionError::ScanParse { .. }
+            | WifiConnectionError::WifiDisabled => None,
+        }
+    }
+}
+
+impl From<WifiError> for WifiConnectionError {
+    fn from(err: WifiError) -> Self {
+        match err {
+            WifiError::WifiDisabled => WifiConnectionError::WifiDisabled,
+            WifiError::IoError(source) => WifiConnectionError::Io(source),
+        }
+    }
+}
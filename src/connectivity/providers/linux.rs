@@ -1,35 +1,86 @@
 use crate::connectivity::{Connectivity, WifiConnectionError};
-use crate::platforms::{Connection, WiFi, WifiError, WifiInterface, AvailableWifi};
+use crate::platforms::{
+    percent_to_dbm, AvailableWifi, Connection, Credential, Psk, Security, WiFi, WifiInterface,
+};
 use std::process::Command;
 
 /// Wireless network connectivity functionality.
 impl Connectivity for WiFi {
     /// Attempts to connect to a wireless network with a given SSID and password.
-    fn connect(&mut self, ssid: &str, password: &str) -> Result<bool, WifiConnectionError> {
-        if !WiFi::is_wifi_enabled().map_err(|err| WifiConnectionError::Other { kind: err })? {
-            return Err(WifiConnectionError::Other {
-                kind: WifiError::WifiDisabled,
+    ///
+    /// `nmcli device wifi connect` only ever takes a bare password, which
+    /// NetworkManager interprets as WPA-PSK/WEP — there's no way to tell it
+    /// to negotiate SAE or 802.1x through that command. So instead this
+    /// builds a connection profile with the right `802-11-wireless-security.*`
+    /// properties up front via `nmcli connection add`, then activates it.
+    fn connect(&mut self, ssid: &str, credential: &Credential) -> Result<bool, WifiConnectionError> {
+        if !self.is_wifi_enabled()? {
+            return Err(WifiConnectionError::WifiDisabled);
+        }
+
+        let mut add_args = vec![
+            "connection".to_string(),
+            "add".to_string(),
+            "type".to_string(),
+            "wifi".to_string(),
+            "ifname".to_string(),
+            self.interface.clone(),
+            "con-name".to_string(),
+            ssid.to_string(),
+            "ssid".to_string(),
+            ssid.to_string(),
+            "802-11-wireless-security.key-mgmt".to_string(),
+            credential.key_mgmt().to_string(),
+        ];
+        match credential {
+            Credential::Open => {}
+            Credential::Wep(key) => {
+                add_args.push("wep-key0".to_string());
+                add_args.push(key.0.clone());
+            }
+            Credential::WpaPersonal(passphrase) | Credential::Wpa3Sae(passphrase) => {
+                add_args.push("802-11-wireless-security.psk".to_string());
+                add_args.push(passphrase.0.clone());
+            }
+            Credential::WpaPersonalPsk(Psk(psk)) => {
+                add_args.push("802-11-wireless-security.psk".to_string());
+                add_args.push(psk.clone());
+            }
+            Credential::WpaEnterprise { identity, password } => {
+                add_args.push("802-1x.eap".to_string());
+                add_args.push("peap".to_string());
+                add_args.push("802-1x.identity".to_string());
+                add_args.push(identity.clone());
+                add_args.push("802-1x.password".to_string());
+                add_args.push(password.clone());
+            }
+        }
+
+        let add_output = Command::new("nmcli")
+            .args(&add_args)
+            .output()
+            .map_err(|source| WifiConnectionError::Connect {
+                ssid: ssid.to_string(),
+                iface: self.interface.clone(),
+                source,
+            })?;
+
+        if !add_output.status.success() {
+            return Err(WifiConnectionError::AddProfile {
+                ssid: ssid.to_string(),
             });
         }
 
-        let output = Command::new("nmcli")
-            .args(&[
-                "d",
-                "wifi",
-                "connect",
-                ssid,
-                "password",
-                &password,
-                "ifname",
-                &self.interface,
-            ])
+        let up_output = Command::new("nmcli")
+            .args(["connection", "up", ssid])
             .output()
-            .map_err(|err| WifiConnectionError::FailedToConnect(format!("{}", err)))?;
+            .map_err(|source| WifiConnectionError::Connect {
+                ssid: ssid.to_string(),
+                iface: self.interface.clone(),
+                source,
+            })?;
 
-        if !String::from_utf8_lossy(&output.stdout)
-            .as_ref()
-            .contains("successfully activated")
-        {
+        if !String::from_utf8_lossy(&up_output.stdout).contains("successfully activated") {
             return Ok(false);
         }
 
@@ -43,57 +94,117 @@ impl Connectivity for WiFi {
     /// Attempts to disconnect from a wireless network currently connected to.
     fn disconnect(&self) -> Result<bool, WifiConnectionError> {
         let output = Command::new("nmcli")
-            .args(&["d", "disconnect", "ifname", &self.interface])
+            .args(["d", "disconnect", "ifname", &self.interface])
             .output()
-            .map_err(|err| WifiConnectionError::FailedToDisconnect(format!("{}", err)))?;
+            .map_err(|_| WifiConnectionError::Disconnect {
+                iface: self.interface.clone(),
+            })?;
 
         Ok(String::from_utf8_lossy(&output.stdout)
             .as_ref()
             .contains("disconnect"))
     }
 
-    // Scan for available networks.
-    fn scan(&self) -> Result<Vec<AvailableWifi>, WifiError> {
-      let mut available_wifis: Vec<AvailableWifi> = Vec::new();
-
-      let output = Command::new("nmcli")
-          .args(&[
-            "-f", "IN-USE,BSSID,SSID,CHAN,SIGNAL,SECURITY",
-            "d", "wifi", "list"])
-          .output()
-          .map_err(|err| WifiError::IoError(err))?;
-
-      let output = String::from_utf8_lossy(&output.stdout);
-      let mut lines = output.lines();
-      lines.next();
-      for line in lines {
-          let mut parts = line.split_whitespace();
-          let temp = parts.next().unwrap().to_string();
-          let mut in_use = false;
-          let mut mac = String::from("");
-          if (temp == "IN-USE") { continue; }
-          else if (temp == "*") { in_use = true; mac = parts.next().unwrap().to_string(); }
-          else                  { mac = temp; }
-          let ssid = parts.next().unwrap().to_string();
-          let channel = parts.next().unwrap().to_string();
-          let signal_level = parts.next().unwrap().to_string();
-          let mut security = parts.next().unwrap().to_string();
-          let mut alt_security = String::from("");
-          if let Ok(temp) = parts.next() { alt_security = temp.to_string(); }
-          if (alt_security != "") { security = alt_security; }
-          
-          let availableWifi = AvailableWifi {
-              ssid,
-              mac,
-              channel,
-              signal_level,
-              security,
-              in_use,
-          };
-
-          available_wifis.push(availableWifi);
-      }
-
-      Ok(available_wifis)
-  }
+    /// Scan for available networks.
+    ///
+    /// Uses `nmcli`'s terse, escaped output (`-t -e yes`) rather than the
+    /// tabular default so SSIDs/security strings containing spaces or
+    /// colons survive parsing intact.
+    fn scan(&self) -> Result<Vec<AvailableWifi>, WifiConnectionError> {
+        let mut available_wifis: Vec<AvailableWifi> = Vec::new();
+
+        let output = Command::new("nmcli")
+            .args([
+                "-t",
+                "-e",
+                "yes",
+                "-f",
+                "IN-USE,BSSID,SSID,CHAN,SIGNAL,SECURITY",
+                "d",
+                "wifi",
+                "list",
+            ])
+            .output()
+            .map_err(WifiConnectionError::Io)?;
+
+        let output = String::from_utf8_lossy(&output.stdout);
+        for line in output.lines() {
+            let fields = split_nmcli_terse_fields(line);
+            let [in_use, mac, ssid, channel, signal_level, security] = <[String; 6]>::try_from(fields)
+                .map_err(|_| WifiConnectionError::ScanParse {
+                    line: line.to_string(),
+                })?;
+
+            let signal_percent = signal_level.parse::<u8>().unwrap_or(0);
+
+            available_wifis.push(AvailableWifi {
+                ssid,
+                mac,
+                channel,
+                signal_level,
+                signal_percent,
+                signal_dbm: percent_to_dbm(signal_percent),
+                security: Security::parse(&security),
+                in_use: in_use == "*",
+            });
+        }
+
+        Ok(available_wifis)
+    }
+}
+
+/// Splits a line of `nmcli -t -e yes` output on `:`, treating `\:` as a
+/// literal colon inside a field rather than a separator.
+fn split_nmcli_terse_fields(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => {
+                if let Some(escaped) = chars.next() {
+                    field.push(escaped);
+                }
+            }
+            ':' => {
+                fields.push(std::mem::take(&mut field));
+            }
+            _ => field.push(c),
+        }
+    }
+    fields.push(field);
+
+    fields
+}
+
+#[cfg(test)]
+mod tests {
+    use super::split_nmcli_terse_fields;
+
+    #[test]
+    fn unescapes_colons_inside_a_field() {
+        assert_eq!(
+            split_nmcli_terse_fields(r"AA\:BB\:CC\:DD\:EE\:FF:My Router:6:80:WPA2"),
+            vec!["AA:BB:CC:DD:EE:FF", "My Router", "6", "80", "WPA2"]
+        );
+    }
+
+    #[test]
+    fn keeps_empty_fields() {
+        assert_eq!(split_nmcli_terse_fields("a::c"), vec!["a", "", "c"]);
+    }
+
+    #[test]
+    fn short_line_yields_fewer_fields() {
+        assert_eq!(split_nmcli_terse_fields("a:b"), vec!["a", "b"]);
+    }
+
+    #[test]
+    fn long_line_yields_more_fields() {
+        assert_eq!(
+            split_nmcli_terse_fields("a:b:c:d:e:f:g"),
+            vec!["a", "b", "c", "d", "e", "f", "g"]
+        );
+    }
 }
@@ -0,0 +1,132 @@
+use crate::connectivity::{Connectivity, WifiConnectionError};
+use crate::platforms::{
+    dbm_to_percent, AvailableWifi, Connection, Credential, Security, WiFi, WifiInterface,
+};
+use std::process::Command;
+
+/// The (private, but long-stable) Airport command line tool, used for
+/// scanning since `networksetup` has no equivalent.
+const AIRPORT_BIN: &str =
+    "/System/Library/PrivateFrameworks/Apple80211.framework/Versions/Current/Resources/airport";
+
+/// The secret `networksetup -setairportnetwork` should be given as its
+/// trailing password argument, for credentials that have one.
+fn airport_password(credential: &Credential) -> Option<&str> {
+    match credential {
+        Credential::Open => None,
+        Credential::Wep(key) => Some(key.0.as_str()),
+        Credential::WpaPersonal(passphrase) | Credential::Wpa3Sae(passphrase) => {
+            Some(passphrase.0.as_str())
+        }
+        Credential::WpaPersonalPsk(psk) => Some(psk.0.as_str()),
+        Credential::WpaEnterprise { password, .. } => Some(password.as_str()),
+    }
+}
+
+/// Wireless network connectivity functionality, backed by `networksetup`
+/// and the Airport command line tool.
+impl Connectivity for WiFi {
+    /// Attempts to connect to a wireless network with a given SSID and password.
+    fn connect(&mut self, ssid: &str, credential: &Credential) -> Result<bool, WifiConnectionError> {
+        if !self.is_wifi_enabled()? {
+            return Err(WifiConnectionError::WifiDisabled);
+        }
+
+        let mut args = vec!["-setairportnetwork", &self.interface, ssid];
+        if let Some(password) = airport_password(credential) {
+            args.push(password);
+        }
+
+        let output = Command::new("networksetup")
+            .args(&args)
+            .output()
+            .map_err(|source| WifiConnectionError::Connect {
+                ssid: ssid.to_string(),
+                iface: self.interface.clone(),
+                source,
+            })?;
+
+        if !String::from_utf8_lossy(&output.stderr).is_empty() {
+            return Ok(false);
+        }
+
+        self.connection = Some(Connection {
+            ssid: String::from(ssid),
+        });
+
+        Ok(true)
+    }
+
+    /// Attempts to disconnect from a wireless network currently connected to.
+    ///
+    /// `networksetup` has no direct "disconnect" verb, so this cycles the
+    /// airport radio off and back on for `self.interface`.
+    fn disconnect(&self) -> Result<bool, WifiConnectionError> {
+        let off = Command::new("networksetup")
+            .args(["-setairportpower", &self.interface, "off"])
+            .output()
+            .map_err(|_| WifiConnectionError::Disconnect {
+                iface: self.interface.clone(),
+            })?;
+
+        Command::new("networksetup")
+            .args(["-setairportpower", &self.interface, "on"])
+            .output()
+            .map_err(|_| WifiConnectionError::Disconnect {
+                iface: self.interface.clone(),
+            })?;
+
+        Ok(off.status.success())
+    }
+
+    /// Scan for available networks.
+    ///
+    /// `airport -s`'s column layout (`SSID BSSID RSSI CHANNEL HT CC
+    /// SECURITY`) is the same one the `wifiscanner` crate parses; the last
+    /// six whitespace-separated columns are fixed width, so the SSID is
+    /// whatever whitespace-separated words remain before them.
+    fn scan(&self) -> Result<Vec<AvailableWifi>, WifiConnectionError> {
+        let output = Command::new(AIRPORT_BIN)
+            .arg("-s")
+            .output()
+            .map_err(WifiConnectionError::Io)?;
+
+        let output = String::from_utf8_lossy(&output.stdout);
+        let mut lines = output.lines();
+        lines.next(); // header
+
+        let mut available_wifis = Vec::new();
+        for line in lines {
+            let columns: Vec<&str> = line.split_whitespace().collect();
+            if columns.len() < 7 {
+                return Err(WifiConnectionError::ScanParse {
+                    line: line.to_string(),
+                });
+            }
+
+            let trailing = columns.len() - 6;
+            let mac = columns[trailing].to_string();
+            let signal_level = columns[trailing + 1].to_string();
+            let channel = columns[trailing + 2].to_string();
+            let security = columns[columns.len() - 1].to_string();
+            let ssid = columns[..trailing].join(" ");
+
+            // `airport -s` reports RSSI directly in dBm, unlike nmcli/netsh's
+            // 0-100 quality percentage.
+            let signal_dbm = signal_level.parse::<i32>().unwrap_or(-100);
+
+            available_wifis.push(AvailableWifi {
+                ssid,
+                mac,
+                channel,
+                signal_level,
+                signal_percent: dbm_to_percent(signal_dbm),
+                signal_dbm,
+                security: Security::parse(&security),
+                in_use: false,
+            });
+        }
+
+        Ok(available_wifis)
+    }
+}
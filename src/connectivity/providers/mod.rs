@@ -0,0 +1,11 @@
+#[cfg(all(target_os = "linux", not(feature = "wpactrl")))]
+pub mod linux;
+
+#[cfg(target_os = "macos")]
+pub mod macos;
+
+#[cfg(target_os = "windows")]
+pub mod windows;
+
+#[cfg(all(target_os = "linux", feature = "wpactrl"))]
+pub mod wpactrl;
@@ -0,0 +1,140 @@
+//! An opt-in Linux backend that talks to `wpa_supplicant`'s control socket
+//! directly via the `wpactrl` crate, for headless/embedded systems that
+//! don't have NetworkManager (and therefore `nmcli`) installed. Enabled
+//! with the `wpactrl` feature, which replaces [`super::linux`] rather than
+//! running alongside it.
+
+use crate::connectivity::{Connectivity, WifiConnectionError};
+use crate::platforms::{
+    dbm_to_percent, AvailableWifi, Connection, Credential, Security, WiFi, WifiInterface,
+};
+use std::io;
+use wpactrl::Client;
+
+fn ctrl_path(iface: &str) -> String {
+    format!("/var/run/wpa_supplicant/{}", iface)
+}
+
+fn open(iface: &str) -> Result<Client, WifiConnectionError> {
+    Client::builder().ctrl_path(ctrl_path(iface)).open().map_err(|source| {
+        WifiConnectionError::Io(io::Error::new(io::ErrorKind::NotFound, source.to_string()))
+    })
+}
+
+fn request(ctrl: &mut Client, cmd: &str) -> Result<String, WifiConnectionError> {
+    let reply = ctrl
+        .request(cmd)
+        .map_err(|source| WifiConnectionError::Io(io::Error::other(source.to_string())))?;
+
+    if reply.trim() == "FAIL" {
+        return Err(WifiConnectionError::Io(io::Error::other(format!(
+            "wpa_supplicant rejected command: {}",
+            cmd
+        ))));
+    }
+
+    Ok(reply)
+}
+
+/// The `key_mgmt` value wpa_supplicant's `SET_NETWORK` expects, which is
+/// dash-separated and uppercase rather than `nmcli`'s lowercase-with-dashes
+/// or `netsh`'s own scheme — none of [`Credential::key_mgmt`]'s values can
+/// be reused as-is.
+fn wpa_supplicant_key_mgmt(credential: &Credential) -> &'static str {
+    match credential {
+        Credential::Open | Credential::Wep(_) => "NONE",
+        Credential::WpaPersonal(_) | Credential::WpaPersonalPsk(_) => "WPA-PSK",
+        Credential::Wpa3Sae(_) => "SAE",
+        Credential::WpaEnterprise { .. } => "WPA-EAP",
+    }
+}
+
+/// Wireless network connectivity functionality, backed directly by the
+/// `wpa_supplicant` control socket instead of `nmcli`.
+impl Connectivity for WiFi {
+    /// Attempts to connect to a wireless network with a given SSID and password.
+    fn connect(&mut self, ssid: &str, credential: &Credential) -> Result<bool, WifiConnectionError> {
+        if !self.is_wifi_enabled()? {
+            return Err(WifiConnectionError::WifiDisabled);
+        }
+
+        let mut ctrl = open(&self.interface)?;
+
+        let network_id = request(&mut ctrl, "ADD_NETWORK")?.trim().to_string();
+
+        let mut set = |field: &str, value: &str| -> Result<(), WifiConnectionError> {
+            request(&mut ctrl, &format!("SET_NETWORK {} {} {}", network_id, field, value))
+                .map(|_| ())
+        };
+
+        set("ssid", &format!("\"{}\"", ssid))?;
+        set("key_mgmt", wpa_supplicant_key_mgmt(credential))?;
+
+        match credential {
+            Credential::Open => {}
+            Credential::Wep(key) => set("wep_key0", &format!("\"{}\"", key.0))?,
+            Credential::WpaPersonal(passphrase) | Credential::Wpa3Sae(passphrase) => {
+                set("psk", &format!("\"{}\"", passphrase.0))?
+            }
+            Credential::WpaPersonalPsk(psk) => set("psk", &psk.0)?,
+            Credential::WpaEnterprise { identity, password } => {
+                set("eap", "PEAP")?;
+                set("identity", &format!("\"{}\"", identity))?;
+                set("password", &format!("\"{}\"", password))?;
+            }
+        }
+
+        request(&mut ctrl, &format!("ENABLE_NETWORK {}", network_id))?;
+        request(&mut ctrl, &format!("SELECT_NETWORK {}", network_id))?;
+
+        self.connection = Some(Connection {
+            ssid: String::from(ssid),
+        });
+
+        Ok(true)
+    }
+
+    /// Attempts to disconnect from a wireless network currently connected to.
+    fn disconnect(&self) -> Result<bool, WifiConnectionError> {
+        let mut ctrl = open(&self.interface)?;
+        request(&mut ctrl, "DISCONNECT")?;
+
+        Ok(true)
+    }
+
+    /// Scan for available networks by issuing `SCAN` and reading back
+    /// `SCAN_RESULTS`, a tab-separated `bssid/frequency/signal/flags/ssid`
+    /// table.
+    fn scan(&self) -> Result<Vec<AvailableWifi>, WifiConnectionError> {
+        let mut ctrl = open(&self.interface)?;
+        request(&mut ctrl, "SCAN")?;
+
+        let results = request(&mut ctrl, "SCAN_RESULTS")?;
+
+        let mut available_wifis = Vec::new();
+        for line in results.lines().skip(1) {
+            let columns: Vec<&str> = line.split('\t').collect();
+            let [mac, _frequency, signal_level, flags, ssid] =
+                <[&str; 5]>::try_from(columns).map_err(|_| WifiConnectionError::ScanParse {
+                    line: line.to_string(),
+                })?;
+
+            // `SCAN_RESULTS`' signal level column is RSSI in dBm, unlike
+            // nmcli/netsh's 0-100 quality percentage.
+            let signal_dbm = signal_level.parse::<i32>().unwrap_or(-100);
+
+            available_wifis.push(AvailableWifi {
+                ssid: ssid.to_string(),
+                mac: mac.to_string(),
+                channel: String::new(),
+                signal_level: signal_level.to_string(),
+                signal_percent: dbm_to_percent(signal_dbm),
+                signal_dbm,
+                security: Security::parse(flags),
+                in_use: false,
+            });
+        }
+
+        Ok(available_wifis)
+    }
+}
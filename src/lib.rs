@@ -0,0 +1,10 @@
+//! Cross-platform wireless network interface management.
+//!
+//! `wifi-rs` wraps the platform-native tooling (`nmcli` on Linux, or the
+//! `wpa_supplicant` control socket with the `wpactrl` feature; `netsh` on
+//! Windows; `networksetup`/`airport` on macOS) behind a single [`platforms::WifiInterface`] /
+//! [`connectivity::Connectivity`] API so callers can scan for, connect to,
+//! and disconnect from wireless networks without caring which OS they're on.
+
+pub mod connectivity;
+pub mod platforms;
@@ -0,0 +1,186 @@
+pub mod security;
+
+pub use security::{Credential, Passphrase, Psk, Security, WepKey};
+
+use std::fmt;
+use std::io;
+
+/// Operations every platform-specific wireless interface must support
+/// outside of connecting/disconnecting/scanning (see
+/// [`crate::connectivity::Connectivity`] for those).
+pub trait WifiInterface {
+    /// Returns whether the wireless radio backing this interface is powered
+    /// on.
+    fn is_wifi_enabled(&self) -> Result<bool, WifiError>;
+}
+
+/// A handle to a single wireless network interface (e.g. `wlan0`), tracking
+/// the connection it last established.
+pub struct WiFi {
+    pub interface: String,
+    pub connection: Option<Connection>,
+}
+
+impl WiFi {
+    /// Creates a handle for the named interface with no active connection.
+    pub fn new(interface: &str) -> Self {
+        WiFi {
+            interface: interface.to_string(),
+            connection: None,
+        }
+    }
+}
+
+/// The wireless network an interface is currently associated with.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Connection {
+    pub ssid: String,
+}
+
+/// A wireless access point discovered during a scan.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AvailableWifi {
+    pub ssid: String,
+    pub mac: String,
+    pub channel: String,
+    /// The raw, backend-specific signal reading (a 0-100 quality from
+    /// `nmcli`/`netsh`, or dBm from `airport`/`wpactrl`), kept for
+    /// compatibility. Prefer `signal_percent`/`signal_dbm` for comparisons.
+    pub signal_level: String,
+    pub signal_percent: u8,
+    pub signal_dbm: i32,
+    pub security: Security,
+    pub in_use: bool,
+}
+
+/// Converts a 0-100 Wi-Fi signal quality percentage (as `nmcli`/`netsh`
+/// report it) to an approximate RSSI in dBm, using the standard
+/// quality→RSSI mapping.
+pub fn percent_to_dbm(percent: u8) -> i32 {
+    (percent as i32 / 2 - 100).clamp(-100, -50)
+}
+
+/// Converts an RSSI in dBm (as `airport`/`wpa_supplicant` report it
+/// directly) back to an approximate 0-100 signal quality percentage.
+pub fn dbm_to_percent(dbm: i32) -> u8 {
+    let clamped = dbm.clamp(-100, -50);
+    ((clamped + 100) * 2) as u8
+}
+
+/// Errors surfaced while querying or toggling the state of a wireless
+/// interface, independent of any particular connection attempt.
+#[derive(Debug)]
+pub enum WifiError {
+    /// The wireless radio is powered off.
+    WifiDisabled,
+    /// The underlying platform command could not be run or returned
+    /// unparseable output.
+    IoError(io::Error),
+}
+
+impl fmt::Display for WifiError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WifiError::WifiDisabled => write!(f, "wifi is disabled"),
+            WifiError::IoError(source) => write!(f, "{}", source),
+        }
+    }
+}
+
+impl std::error::Error for WifiError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            WifiError::IoError(source) => Some(source),
+            WifiError::WifiDisabled => None,
+        }
+    }
+}
+
+#[cfg(all(target_os = "linux", not(feature = "wpactrl")))]
+impl WifiInterface for WiFi {
+    fn is_wifi_enabled(&self) -> Result<bool, WifiError> {
+        let output = std::process::Command::new("nmcli")
+            .args(["radio", "wifi"])
+            .output()
+            .map_err(WifiError::IoError)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).trim() == "enabled")
+    }
+}
+
+#[cfg(all(target_os = "linux", feature = "wpactrl"))]
+impl WifiInterface for WiFi {
+    /// Asks `wpa_supplicant` itself rather than `nmcli`, since the whole
+    /// point of the `wpactrl` feature is running without NetworkManager.
+    fn is_wifi_enabled(&self) -> Result<bool, WifiError> {
+        let mut ctrl = wpactrl::Client::builder()
+            .ctrl_path(format!("/var/run/wpa_supplicant/{}", self.interface))
+            .open()
+            .map_err(|source| WifiError::IoError(io::Error::other(source.to_string())))?;
+
+        let status = ctrl
+            .request("STATUS")
+            .map_err(|source| WifiError::IoError(io::Error::other(source.to_string())))?;
+
+        Ok(!status
+            .lines()
+            .any(|line| line == "wpa_state=INTERFACE_DISABLED"))
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl WifiInterface for WiFi {
+    fn is_wifi_enabled(&self) -> Result<bool, WifiError> {
+        let output = std::process::Command::new("netsh")
+            .args(["interface", "show", "interface"])
+            .output()
+            .map_err(WifiError::IoError)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).contains("Wireless"))
+    }
+}
+
+#[cfg(target_os = "macos")]
+impl WifiInterface for WiFi {
+    fn is_wifi_enabled(&self) -> Result<bool, WifiError> {
+        let output = std::process::Command::new("networksetup")
+            .args(["-getairportpower", &self.interface])
+            .output()
+            .map_err(WifiError::IoError)?;
+
+        Ok(String::from_utf8_lossy(&output.stdout).contains("On"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{dbm_to_percent, percent_to_dbm};
+
+    #[test]
+    fn percent_to_dbm_clamps_to_the_usable_range() {
+        assert_eq!(percent_to_dbm(0), -100);
+        assert_eq!(percent_to_dbm(100), -50);
+    }
+
+    #[test]
+    fn percent_to_dbm_interpolates() {
+        assert_eq!(percent_to_dbm(50), -75);
+    }
+
+    #[test]
+    fn dbm_to_percent_clamps_to_the_usable_range() {
+        assert_eq!(dbm_to_percent(-100), 0);
+        assert_eq!(dbm_to_percent(-50), 100);
+        assert_eq!(dbm_to_percent(-120), 0);
+        assert_eq!(dbm_to_percent(0), 100);
+    }
+
+    #[test]
+    fn round_trips_through_both_conversions() {
+        for percent in [0u8, 20, 50, 80, 100] {
+            assert_eq!(dbm_to_percent(percent_to_dbm(percent)), percent);
+        }
+    }
+}
@@ -0,0 +1,124 @@
+/// A human-readable WPA/WPA3 passphrase (8-63 ASCII characters).
+#[derive(Debug, Clone)]
+pub struct Passphrase(pub String);
+
+/// A precomputed WPA/WPA2 PSK, given as 64 hex characters rather than
+/// derived from a passphrase.
+#[derive(Debug, Clone)]
+pub struct Psk(pub String);
+
+/// A WEP key: 5/13 ASCII characters or 10/26 hex characters.
+#[derive(Debug, Clone)]
+pub struct WepKey(pub String);
+
+/// The secret required to authenticate against an access point, typed by
+/// the scheme it applies to so a caller can't hand a PSK to an open network
+/// or a passphrase where the backend needs raw hex.
+#[derive(Debug, Clone)]
+pub enum Credential {
+    /// No authentication required.
+    Open,
+    Wep(WepKey),
+    /// WPA/WPA2-Personal with a human passphrase, to be hashed into a PSK.
+    WpaPersonal(Passphrase),
+    /// WPA/WPA2-Personal with an already-derived 64-hex-character PSK.
+    WpaPersonalPsk(Psk),
+    /// WPA3-Personal (SAE), always a passphrase.
+    Wpa3Sae(Passphrase),
+    /// WPA/WPA2/WPA3-Enterprise (802.1X), authenticated via an identity and
+    /// a password handed to the EAP method rather than the link itself.
+    WpaEnterprise { identity: String, password: String },
+}
+
+impl Credential {
+    /// The `nmcli` `802-11-wireless-security.key-mgmt` value that matches
+    /// this credential.
+    pub fn key_mgmt(&self) -> &'static str {
+        match self {
+            Credential::Open => "none",
+            Credential::Wep(_) => "none",
+            Credential::WpaPersonal(_) | Credential::WpaPersonalPsk(_) => "wpa-psk",
+            Credential::Wpa3Sae(_) => "sae",
+            Credential::WpaEnterprise { .. } => "wpa-eap",
+        }
+    }
+}
+
+/// The authentication/encryption scheme an access point advertises in a
+/// scan, as opposed to [`Credential`] which carries the secret needed to
+/// actually authenticate against one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Security {
+    Open,
+    Wep,
+    WpaPersonal,
+    Wpa3Sae,
+    WpaEnterprise,
+    /// The scan output used a security token this crate doesn't recognize.
+    Unknown,
+}
+
+impl Security {
+    /// Parses an `nmcli`/`netsh` security token (e.g. `WPA2`, `WPA2-Personal`,
+    /// `WPA3-Enterprise`, `--`) into a [`Security`].
+    pub fn parse(token: &str) -> Security {
+        let token = token.trim();
+        if token.is_empty() || token == "--" || token.eq_ignore_ascii_case("open") {
+            return Security::Open;
+        }
+
+        let lower = token.to_ascii_lowercase();
+        if lower.contains("eap") || lower.contains("enterprise") || lower.contains("802.1x") {
+            Security::WpaEnterprise
+        } else if lower.contains("sae") || lower.contains("wpa3") {
+            Security::Wpa3Sae
+        } else if lower.contains("wpa") {
+            Security::WpaPersonal
+        } else if lower.contains("wep") {
+            Security::Wep
+        } else {
+            Security::Unknown
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Security;
+
+    #[test]
+    fn parses_open() {
+        assert_eq!(Security::parse("--"), Security::Open);
+        assert_eq!(Security::parse(""), Security::Open);
+        assert_eq!(Security::parse("Open"), Security::Open);
+    }
+
+    #[test]
+    fn parses_wep() {
+        assert_eq!(Security::parse("WEP"), Security::Wep);
+    }
+
+    #[test]
+    fn parses_wpa_personal() {
+        assert_eq!(Security::parse("WPA2"), Security::WpaPersonal);
+        assert_eq!(Security::parse("WPA2-Personal"), Security::WpaPersonal);
+    }
+
+    #[test]
+    fn parses_wpa3_sae() {
+        assert_eq!(Security::parse("WPA3-Personal"), Security::Wpa3Sae);
+        assert_eq!(Security::parse("SAE"), Security::Wpa3Sae);
+    }
+
+    #[test]
+    fn parses_wpa_enterprise() {
+        assert_eq!(Security::parse("WPA2-Enterprise"), Security::WpaEnterprise);
+        assert_eq!(Security::parse("802.1x"), Security::WpaEnterprise);
+    }
+
+    #[test]
+    fn parses_unknown_token_as_unknown() {
+        assert_eq!(Security::parse("some-made-up-token"), Security::Unknown);
+    }
+}